@@ -0,0 +1,152 @@
+use std::{fs, path::PathBuf};
+
+use clap::{Parser, Subcommand};
+
+use crate::{format::format_text, options::DjangoFmtOptions, resolver::Resolver};
+
+/// Command-line entry point for `djangofmt`.
+#[derive(Parser)]
+#[command(name = "djangofmt", version, about)]
+pub struct Cli {
+    /// Template files to format in place.
+    files: Vec<PathBuf>,
+
+    /// Loads options from this config file instead of searching each file's ancestors for one.
+    #[arg(long, global = true)]
+    config: Option<PathBuf>,
+
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Runs `djangofmt` as a Language Server Protocol server over stdio.
+    Server,
+}
+
+impl Cli {
+    /// Runs the command this [`Cli`] was parsed into.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the LSP server fails to start, the `--config` file can't be loaded, or
+    /// a file can't be read or written back.
+    pub fn run(self) -> Result<(), Box<dyn std::error::Error + Sync + Send>> {
+        let explicit_options = self
+            .config
+            .as_deref()
+            .map(DjangoFmtOptions::from_file)
+            .transpose()?;
+
+        match self.command {
+            Some(Command::Server) => crate::lsp::run(explicit_options),
+            None => self.format_files(explicit_options),
+        }
+    }
+
+    fn format_files(
+        self,
+        explicit_options: Option<DjangoFmtOptions>,
+    ) -> Result<(), Box<dyn std::error::Error + Sync + Send>> {
+        let mut resolver = Resolver::new();
+        let mut had_format_error = false;
+        for path in &self.files {
+            let options = match &explicit_options {
+                Some(options) => options.clone(),
+                None => resolver.resolve(path)?.unwrap_or_default(),
+            };
+
+            let source = fs::read_to_string(path)?;
+            let language = (&options.profile).into();
+            let formatted = format_text(&source, language, &options).unwrap_or_else(|err| {
+                eprintln!("djangofmt: {}: {err}", path.display());
+                had_format_error = true;
+                source.clone()
+            });
+            if formatted != source {
+                fs::write(path, formatted)?;
+            }
+        }
+
+        if had_format_error {
+            return Err("failed to format one or more files".into());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use tempfile::tempdir;
+
+    use super::*;
+
+    #[test]
+    fn test_explicit_config_bypasses_resolver() {
+        let project_dir = tempdir().unwrap();
+        // Malformed: if the resolver were still consulted despite `--config`, this would error.
+        fs::write(
+            project_dir.path().join("pyproject.toml"),
+            "not valid toml [[[",
+        )
+        .unwrap();
+        let file_path = project_dir.path().join("template.html");
+        fs::write(&file_path, "<p>hi</p>\n").unwrap();
+
+        let config_dir = tempdir().unwrap();
+        let config_path = config_dir.path().join("djangofmt.toml");
+        fs::write(&config_path, "line_length = 100\n").unwrap();
+
+        let cli = Cli {
+            files: vec![file_path],
+            config: Some(config_path),
+            command: None,
+        };
+        assert!(cli.run().is_ok());
+    }
+
+    #[test]
+    fn test_format_files_writes_back_only_when_changed() {
+        let temp_dir = tempdir().unwrap();
+        let file_path = temp_dir.path().join("template.html");
+        fs::write(&file_path, "<p>hi</p>").unwrap();
+
+        let options = DjangoFmtOptions::default();
+        let language = (&options.profile).into();
+        let expected = format_text("<p>hi</p>", language, &options).unwrap();
+
+        let cli = Cli {
+            files: vec![file_path.clone()],
+            config: None,
+            command: None,
+        };
+        cli.run().unwrap();
+
+        assert_eq!(fs::read_to_string(&file_path).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_format_files_resolves_shared_config_across_multiple_files() {
+        let temp_dir = tempdir().unwrap();
+        fs::write(
+            temp_dir.path().join("pyproject.toml"),
+            "[tool.djangofmt]\nline_length=100\n",
+        )
+        .unwrap();
+
+        let first_path = temp_dir.path().join("a.html");
+        let second_path = temp_dir.path().join("b.html");
+        fs::write(&first_path, "<p>a</p>").unwrap();
+        fs::write(&second_path, "<p>b</p>").unwrap();
+
+        let cli = Cli {
+            files: vec![first_path, second_path],
+            config: None,
+            command: None,
+        };
+        assert!(cli.run().is_ok());
+    }
+}
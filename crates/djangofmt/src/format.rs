@@ -0,0 +1,31 @@
+use markup_fmt::{
+    config::{FormatOptions, LayoutOptions},
+    format_text as markup_format_text, FormatError, Language,
+};
+
+use crate::options::DjangoFmtOptions;
+
+/// Formats a Django/Jinja template, delegating layout and printing to `markup_fmt`.
+///
+/// # Errors
+///
+/// Returns an error if `source` fails to parse as `language`.
+pub fn format_text(
+    source: &str,
+    language: Language,
+    options: &DjangoFmtOptions,
+) -> Result<String, FormatError> {
+    let format_options = FormatOptions {
+        layout: LayoutOptions {
+            print_width: options.line_length,
+            indent_width: options.indent_width,
+            ..LayoutOptions::default()
+        },
+        ..FormatOptions::default()
+    };
+
+    // No embedded `<script>`/`<style>` formatter is wired in yet, so leave that code untouched.
+    markup_format_text(source, language, &format_options, |code, _hints| {
+        Ok(code.into())
+    })
+}
@@ -0,0 +1,5 @@
+pub mod cli;
+pub mod format;
+pub mod lsp;
+pub mod options;
+pub mod resolver;
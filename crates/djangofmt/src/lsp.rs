@@ -0,0 +1,213 @@
+use std::collections::HashMap;
+
+use lsp_server::{Connection, ExtractError, Message, Request, RequestId, Response};
+use lsp_types::{
+    notification::{DidChangeTextDocument, DidOpenTextDocument, Notification as _},
+    request::{Formatting, Request as _},
+    InitializeParams, OneOf, PositionEncodingKind, ServerCapabilities, TextDocumentSyncCapability,
+    TextDocumentSyncKind, TextEdit, Url,
+};
+
+use crate::{options::DjangoFmtOptions, resolver::Resolver};
+
+/// Runs `djangofmt` as a Language Server Protocol server over stdio, formatting Django/Jinja
+/// templates on `textDocument/formatting` requests.
+///
+/// If `explicit_options` is given (from `--config`), every document is formatted with it instead
+/// of resolving a `pyproject.toml`/`djangofmt.toml` for its own path.
+///
+/// # Errors
+///
+/// Returns an error if the stdio transport or the LSP handshake fails.
+pub fn run(
+    explicit_options: Option<DjangoFmtOptions>,
+) -> Result<(), Box<dyn std::error::Error + Sync + Send>> {
+    let (connection, io_threads) = Connection::stdio();
+
+    let server_capabilities = serde_json::to_value(ServerCapabilities {
+        document_formatting_provider: Some(OneOf::Left(true)),
+        text_document_sync: Some(TextDocumentSyncCapability::Kind(TextDocumentSyncKind::FULL)),
+        position_encoding: Some(PositionEncodingKind::UTF16),
+        ..ServerCapabilities::default()
+    })?;
+    let initialize_params = connection.initialize(server_capabilities)?;
+    let _params: InitializeParams = serde_json::from_value(initialize_params)?;
+
+    let mut server = Server::new(explicit_options);
+    server.run(&connection)?;
+
+    io_threads.join()?;
+    Ok(())
+}
+
+/// Keeps the in-memory buffer of every document the client has opened, and a [`Resolver`] so
+/// each document's formatting options are resolved (and cached) from its own `pyproject.toml`,
+/// unless `explicit_options` overrides that lookup for every document.
+struct Server {
+    documents: HashMap<Url, String>,
+    resolver: Resolver,
+    explicit_options: Option<DjangoFmtOptions>,
+}
+
+impl Server {
+    fn new(explicit_options: Option<DjangoFmtOptions>) -> Self {
+        Self {
+            documents: HashMap::new(),
+            resolver: Resolver::new(),
+            explicit_options,
+        }
+    }
+
+    fn run(
+        &mut self,
+        connection: &Connection,
+    ) -> Result<(), Box<dyn std::error::Error + Sync + Send>> {
+        for message in &connection.receiver {
+            match message {
+                Message::Request(request) => {
+                    if connection.handle_shutdown(&request)? {
+                        return Ok(());
+                    }
+                    self.handle_request(connection, request)?;
+                }
+                Message::Notification(notification) => self.handle_notification(notification),
+                Message::Response(_) => {}
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_request(
+        &mut self,
+        connection: &Connection,
+        request: Request,
+    ) -> Result<(), Box<dyn std::error::Error + Sync + Send>> {
+        if request.method.as_str() == Formatting::METHOD {
+            let (id, params) = cast_request::<Formatting>(request)?;
+            let edits = self.format_document(&params.text_document.uri);
+            connection
+                .sender
+                .send(Message::Response(Response::new_ok(id, edits)))?;
+        }
+        Ok(())
+    }
+
+    fn handle_notification(&mut self, notification: lsp_server::Notification) {
+        match notification.method.as_str() {
+            DidOpenTextDocument::METHOD => {
+                if let Ok(params) = serde_json::from_value::<lsp_types::DidOpenTextDocumentParams>(
+                    notification.params,
+                ) {
+                    self.documents
+                        .insert(params.text_document.uri, params.text_document.text);
+                }
+            }
+            DidChangeTextDocument::METHOD => {
+                if let Ok(params) = serde_json::from_value::<lsp_types::DidChangeTextDocumentParams>(
+                    notification.params,
+                ) {
+                    // We only advertise full-document sync, so the last change carries the
+                    // entire new content.
+                    if let Some(change) = params.content_changes.into_iter().last() {
+                        self.documents.insert(params.text_document.uri, change.text);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Formats the document at `uri` with the options governing its on-disk path, returning a
+    /// single full-document [`TextEdit`] (or none if the document isn't open or isn't formattable).
+    fn format_document(&mut self, uri: &Url) -> Option<Vec<TextEdit>> {
+        let source = self.documents.get(uri)?;
+        let options = match &self.explicit_options {
+            Some(options) => options.clone(),
+            None => {
+                let path = uri.to_file_path().ok()?;
+                self.resolver
+                    .resolve(&path)
+                    .ok()
+                    .flatten()
+                    .unwrap_or_default()
+            }
+        };
+
+        let formatted = format_source(source, &options);
+        if formatted == *source {
+            return Some(vec![]);
+        }
+
+        Some(vec![TextEdit {
+            range: full_document_range(),
+            new_text: formatted,
+        }])
+    }
+}
+
+/// Formats `source` as a Django/Jinja template using the resolved options.
+fn format_source(source: &str, options: &DjangoFmtOptions) -> String {
+    let language = (&options.profile).into();
+    crate::format::format_text(source, language, options).unwrap_or_else(|_| source.to_string())
+}
+
+/// A range spanning the whole document, used for a full-document `TextEdit`. `(u32::MAX,
+/// u32::MAX)` is the conventional "end of document" sentinel for LSP clients, which clamp it to
+/// the real last position rather than requiring the server to compute exact line/column counts.
+fn full_document_range() -> lsp_types::Range {
+    lsp_types::Range {
+        start: lsp_types::Position::new(0, 0),
+        end: lsp_types::Position::new(u32::MAX, u32::MAX),
+    }
+}
+
+fn cast_request<R>(request: Request) -> Result<(RequestId, R::Params), ExtractError<Request>>
+where
+    R: lsp_types::request::Request,
+{
+    request.extract(R::METHOD)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_full_document_range_uses_max_sentinel() {
+        let range = full_document_range();
+        assert_eq!(range.start, lsp_types::Position::new(0, 0));
+        assert_eq!(range.end, lsp_types::Position::new(u32::MAX, u32::MAX));
+    }
+
+    #[test]
+    fn test_format_document_returns_none_for_unopened_document() {
+        let mut server = Server::new(None);
+        let uri = Url::parse("file:///tmp/unopened.html").unwrap();
+        assert_eq!(server.format_document(&uri), None);
+    }
+
+    #[test]
+    fn test_format_document_returns_empty_edits_when_already_formatted() {
+        let mut server = Server::new(None);
+        let uri = Url::parse("file:///tmp/already-formatted.html").unwrap();
+        server
+            .documents
+            .insert(uri.clone(), "<p>hi</p>\n".to_string());
+
+        let edits = server.format_document(&uri).unwrap();
+        assert_eq!(edits, vec![]);
+    }
+
+    #[test]
+    fn test_format_document_with_explicit_options_skips_path_resolution() {
+        // `untitled:` URIs have no on-disk path, so this only succeeds if explicit_options is
+        // used directly instead of falling through to the resolver's `to_file_path` lookup.
+        let mut server = Server::new(Some(DjangoFmtOptions::default()));
+        let uri = Url::parse("untitled:Untitled-1").unwrap();
+        server
+            .documents
+            .insert(uri.clone(), "<p>hi</p>\n".to_string());
+
+        assert!(server.format_document(&uri).is_some());
+    }
+}
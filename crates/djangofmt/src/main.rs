@@ -0,0 +1,6 @@
+use clap::Parser;
+use djangofmt::cli::Cli;
+
+fn main() -> Result<(), Box<dyn std::error::Error + Sync + Send>> {
+    Cli::parse().run()
+}
@@ -1,11 +1,58 @@
 use markup_fmt::Language;
 use serde::{Deserialize, Serialize};
 use std::{
-    fs,
+    fmt, fs,
     path::{Path, PathBuf},
     str::FromStr,
 };
 
+/// Error produced while discovering or parsing a djangofmt config file.
+#[derive(Debug)]
+pub enum ConfigError {
+    /// The config file (or an `extend` target it points to) could not be read.
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    /// The config file's TOML could not be parsed.
+    Parse {
+        path: PathBuf,
+        source: toml::de::Error,
+    },
+    /// An `extend` chain referenced a config file that was already being resolved.
+    ExtendCycle { path: PathBuf },
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io { path, source } => {
+                write!(f, "failed to read `{}`: {source}", path.display())
+            }
+            Self::Parse { path, source } => {
+                write!(f, "failed to parse `{}`: {source}", path.display())
+            }
+            Self::ExtendCycle { path } => {
+                write!(
+                    f,
+                    "cycle detected while resolving `extend` chain at `{}`",
+                    path.display()
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io { source, .. } => Some(source),
+            Self::Parse { source, .. } => Some(source),
+            Self::ExtendCycle { .. } => None,
+        }
+    }
+}
+
 #[derive(Deserialize)]
 struct PyProject {
     tool: Option<Tool>,
@@ -14,7 +61,46 @@ struct PyProject {
 #[derive(Deserialize)]
 struct Tool {
     #[serde(default)]
-    djangofmt: Option<DjangoFmtOptions>,
+    djangofmt: Option<RawDjangoFmtOptions>,
+}
+
+/// Mirrors [`DjangoFmtOptions`], but every field is optional so that deserializing a
+/// `[tool.djangofmt]` table can distinguish a key that is absent (inherit from `extend`) from
+/// one that is explicitly set to its default value (override `extend`).
+#[derive(Deserialize, Default, Clone)]
+#[serde(default)]
+struct RawDjangoFmtOptions {
+    line_length: Option<usize>,
+    indent_width: Option<usize>,
+    custom_blocks: Option<Vec<String>>,
+    profile: Option<Profile>,
+    /// Path (relative to this config file) of another config to inherit settings from.
+    extend: Option<String>,
+}
+
+impl RawDjangoFmtOptions {
+    /// Layers `self`'s explicitly-present fields on top of `base`, keeping `base`'s value for
+    /// anything `self` leaves unset.
+    fn merge_over(self, base: Self) -> Self {
+        Self {
+            line_length: self.line_length.or(base.line_length),
+            indent_width: self.indent_width.or(base.indent_width),
+            custom_blocks: self.custom_blocks.or(base.custom_blocks),
+            profile: self.profile.or(base.profile),
+            extend: None,
+        }
+    }
+}
+
+impl From<RawDjangoFmtOptions> for DjangoFmtOptions {
+    fn from(raw: RawDjangoFmtOptions) -> Self {
+        Self::new(
+            raw.line_length,
+            raw.indent_width,
+            raw.custom_blocks,
+            raw.profile,
+        )
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize, clap::ValueEnum, Default)]
@@ -27,8 +113,9 @@ pub enum Profile {
 impl From<&Profile> for Language {
     fn from(profile: &Profile) -> Self {
         match profile {
-            Profile::Django => Self::Django,
-            Profile::Jinja => Self::Jinja,
+            // markup_fmt has no dedicated Django variant; its Jinja parser already covers
+            // Django template syntax closely enough to reuse for both profiles.
+            Profile::Django | Profile::Jinja => Self::Jinja,
         }
     }
 }
@@ -86,32 +173,105 @@ impl DjangoFmtOptions {
             profile: profile.unwrap_or(default.profile),
         }
     }
+
+    /// Loads options from an explicit config file, bypassing the upward config search used by
+    /// [`load_options`]. The file may either be a standalone `djangofmt.toml`-style file (parsed
+    /// directly) or a `pyproject.toml` (parsed through its `[tool.djangofmt]` table). Falls back
+    /// to [`DjangoFmtOptions::default`] if it has no djangofmt settings.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, ConfigError> {
+        Ok(load_options_from_config_file(path)?.unwrap_or_default())
+    }
+}
+
+/// File names checked, in order, at each ancestor directory by [`find_config_file`]. A standalone
+/// djangofmt config takes precedence over a `pyproject.toml` in the same directory.
+const CONFIG_FILE_NAMES: [&str; 3] = [".djangofmt.toml", "djangofmt.toml", "pyproject.toml"];
+
+/// Returns whether `path`'s file name is `pyproject.toml`, i.e. whether its djangofmt settings
+/// live under a `[tool.djangofmt]` table rather than at the file's top level.
+fn is_pyproject_toml(path: &Path) -> bool {
+    path.file_name().and_then(|name| name.to_str()) == Some("pyproject.toml")
 }
 
-/// Loads `FormatterOptions` from a given `pyproject.toml` file
-fn load_options_from_pyproject_toml(content: &str) -> Option<DjangoFmtOptions> {
-    let pyproject: PyProject = toml::from_str(content).expect("Failed to parse pyproject.toml");
-    let djangofmt = pyproject.tool.and_then(|t| t.djangofmt).unwrap_or_default();
-    Some(djangofmt)
+/// Loads `DjangoFmtOptions` from a given config file, following its `extend` chain (if any) and
+/// merging inherited settings underneath this file's own.
+pub(crate) fn load_options_from_config_file<P: AsRef<Path>>(
+    config_path: P,
+) -> Result<Option<DjangoFmtOptions>, ConfigError> {
+    let mut visited = Vec::new();
+    let raw = resolve_raw_options(config_path.as_ref(), &mut visited)?;
+    Ok(Some(raw.into()))
 }
 
-/// Finds the `pyproject.toml` settings file by traversing directories upward from the given path
-fn find_pyproject_toml<P: AsRef<Path>>(start_path: P) -> Option<PathBuf> {
+/// Reads and parses a single config file, recursively resolving and merging its `extend` target
+/// underneath it. `visited` tracks the absolute paths already walked in this chain so that an
+/// `extend` cycle is reported instead of recursing forever.
+fn resolve_raw_options(
+    config_path: &Path,
+    visited: &mut Vec<PathBuf>,
+) -> Result<RawDjangoFmtOptions, ConfigError> {
+    let absolute = config_path
+        .canonicalize()
+        .unwrap_or_else(|_| config_path.to_path_buf());
+    if visited.contains(&absolute) {
+        return Err(ConfigError::ExtendCycle {
+            path: config_path.to_path_buf(),
+        });
+    }
+    visited.push(absolute);
+
+    let content = fs::read_to_string(config_path).map_err(|source| ConfigError::Io {
+        path: config_path.to_path_buf(),
+        source,
+    })?;
+    let raw = if is_pyproject_toml(config_path) {
+        let pyproject: PyProject =
+            toml::from_str(&content).map_err(|source| ConfigError::Parse {
+                path: config_path.to_path_buf(),
+                source,
+            })?;
+        pyproject.tool.and_then(|t| t.djangofmt).unwrap_or_default()
+    } else {
+        toml::from_str(&content).map_err(|source| ConfigError::Parse {
+            path: config_path.to_path_buf(),
+            source,
+        })?
+    };
+
+    let Some(extend) = &raw.extend else {
+        return Ok(raw);
+    };
+
+    let base_dir = config_path.parent().unwrap_or_else(|| Path::new("."));
+    let base = resolve_raw_options(&base_dir.join(extend), visited)?;
+    Ok(raw.merge_over(base))
+}
+
+/// Finds the settings file governing `start_path` by walking its ancestors and, in each
+/// directory, looking for `.djangofmt.toml`, then `djangofmt.toml`, then `pyproject.toml` —
+/// stopping at the first match.
+pub(crate) fn find_config_file<P: AsRef<Path>>(start_path: P) -> Option<PathBuf> {
     for directory in start_path.as_ref().ancestors() {
-        let pyproject_toml = directory.join("pyproject.toml");
-        if pyproject_toml.is_file() {
-            return Some(pyproject_toml);
+        for name in CONFIG_FILE_NAMES {
+            let candidate = directory.join(name);
+            if candidate.is_file() {
+                return Some(candidate);
+            }
         }
     }
     None
 }
 
-/// Loads user configured options from the nearest `pyproject.toml` file from the given path
-pub fn load_options<P: AsRef<Path>>(start_path: P) -> Option<DjangoFmtOptions> {
-    let pyproject_path =
-        find_pyproject_toml(start_path.as_ref()).expect("Failed to find pyproject.toml");
-    let content = fs::read_to_string(pyproject_path).ok()?;
-    load_options_from_pyproject_toml(&content)
+/// Loads user configured options from the nearest config file from the given path (see
+/// [`find_config_file`] for the precedence between `.djangofmt.toml`, `djangofmt.toml` and
+/// `pyproject.toml`). Returns `Ok(None)` when no config file is found anywhere above `start_path`.
+pub fn load_options<P: AsRef<Path>>(
+    start_path: P,
+) -> Result<Option<DjangoFmtOptions>, ConfigError> {
+    let Some(config_path) = find_config_file(start_path.as_ref()) else {
+        return Ok(None);
+    };
+    load_options_from_config_file(config_path)
 }
 
 #[cfg(test)]
@@ -120,27 +280,56 @@ mod tests {
     use tempfile::tempdir;
 
     #[test]
-    fn test_find_pyproject_toml_should_return_none() {
+    fn test_find_config_file_should_return_none() {
         let temp_dir = tempdir().unwrap();
-        assert_eq!(find_pyproject_toml(temp_dir), None);
+        assert_eq!(find_config_file(temp_dir), None);
     }
 
     #[test]
-    fn test_find_pyproject_toml_in_current_dir() {
+    fn test_find_config_file_in_current_dir() {
         let temp_dir = tempdir().unwrap();
         let pyproject_path = temp_dir.path().join("pyproject.toml");
         fs::write(&pyproject_path, "").unwrap();
-        assert_eq!(find_pyproject_toml(temp_dir), Some(pyproject_path));
+        assert_eq!(find_config_file(temp_dir), Some(pyproject_path));
     }
 
     #[test]
-    fn test_find_pyproject_toml_in_parent_dir() {
+    fn test_find_config_file_in_parent_dir() {
         let parent_dir = tempdir().unwrap();
         let pyproject_path = parent_dir.path().join("pyproject.toml");
         fs::write(&pyproject_path, "").unwrap();
         fs::create_dir(parent_dir.path().join("child_dir")).ok();
         let child_dir = parent_dir.path().join("child_dir");
-        assert_eq!(find_pyproject_toml(child_dir), Some(pyproject_path));
+        assert_eq!(find_config_file(child_dir), Some(pyproject_path));
+    }
+
+    #[test]
+    fn test_find_config_file_prefers_standalone_over_pyproject_toml() {
+        let temp_dir = tempdir().unwrap();
+        fs::write(temp_dir.path().join("pyproject.toml"), "").unwrap();
+        let standalone_path = temp_dir.path().join("djangofmt.toml");
+        fs::write(&standalone_path, "").unwrap();
+        assert_eq!(find_config_file(temp_dir.path()), Some(standalone_path));
+    }
+
+    #[test]
+    fn test_find_config_file_prefers_dotfile_over_plain_standalone() {
+        let temp_dir = tempdir().unwrap();
+        fs::write(temp_dir.path().join("djangofmt.toml"), "").unwrap();
+        let dotfile_path = temp_dir.path().join(".djangofmt.toml");
+        fs::write(&dotfile_path, "").unwrap();
+        assert_eq!(find_config_file(temp_dir.path()), Some(dotfile_path));
+    }
+
+    #[test]
+    fn test_load_options_from_standalone_djangofmt_toml() {
+        let temp_dir = tempdir().unwrap();
+        let config_path = temp_dir.path().join("djangofmt.toml");
+        fs::write(&config_path, "line_length=90\nindent_width=2\n").unwrap();
+
+        let result = load_options(&config_path).unwrap().unwrap();
+        assert_eq!(result.line_length, 90);
+        assert_eq!(result.indent_width, 2);
     }
 
     #[test]
@@ -156,7 +345,7 @@ mod tests {
             ";
 
         fs::write(&pyproject_path, pyproject_content).unwrap();
-        let result = load_options(&pyproject_path);
+        let result = load_options(&pyproject_path).unwrap();
         assert_eq!(
             result,
             Some(DjangoFmtOptions {
@@ -167,4 +356,89 @@ mod tests {
             })
         );
     }
+
+    #[test]
+    fn test_load_options_with_extend_inherits_unset_fields() {
+        let temp_dir = tempdir().unwrap();
+        let base_path = temp_dir.path().join("base.toml");
+        fs::write(
+            &base_path,
+            r"
+            line_length=100
+            custom_blocks=['foo']
+            ",
+        )
+        .unwrap();
+
+        let child_path = temp_dir.path().join("pyproject.toml");
+        fs::write(
+            &child_path,
+            r#"
+            [tool.djangofmt]
+            extend="base.toml"
+            indent_width=2
+            "#,
+        )
+        .unwrap();
+
+        let result = load_options(&child_path).unwrap().unwrap();
+        assert_eq!(result.line_length, 100);
+        assert_eq!(result.custom_blocks, vec!["foo".to_string()]);
+        assert_eq!(result.indent_width, 2);
+    }
+
+    #[test]
+    fn test_load_options_with_extend_overrides_base_fields() {
+        let temp_dir = tempdir().unwrap();
+        let base_path = temp_dir.path().join("base.toml");
+        fs::write(&base_path, "line_length=100\n").unwrap();
+
+        let child_path = temp_dir.path().join("pyproject.toml");
+        fs::write(
+            &child_path,
+            "[tool.djangofmt]\nextend=\"base.toml\"\nline_length=200\n",
+        )
+        .unwrap();
+
+        let result = load_options(&child_path).unwrap().unwrap();
+        assert_eq!(result.line_length, 200);
+    }
+
+    #[test]
+    fn test_load_options_with_extend_cycle_errors() {
+        let temp_dir = tempdir().unwrap();
+        let a_path = temp_dir.path().join("a.toml");
+        let b_path = temp_dir.path().join("b.toml");
+        fs::write(&a_path, "extend=\"b.toml\"\n").unwrap();
+        fs::write(&b_path, "extend=\"a.toml\"\n").unwrap();
+
+        let result = load_options_from_config_file(&a_path);
+        assert!(matches!(result, Err(ConfigError::ExtendCycle { .. })));
+    }
+
+    #[test]
+    fn test_load_options_from_config_file_with_invalid_toml_errors() {
+        let temp_dir = tempdir().unwrap();
+        let pyproject_path = temp_dir.path().join("pyproject.toml");
+        fs::write(&pyproject_path, "not valid toml [[[").unwrap();
+
+        let result = load_options_from_config_file(&pyproject_path);
+        assert!(matches!(result, Err(ConfigError::Parse { .. })));
+    }
+
+    #[test]
+    fn test_load_options_without_pyproject_toml_returns_none() {
+        let temp_dir = tempdir().unwrap();
+        assert_eq!(load_options(temp_dir.path()).unwrap(), None);
+    }
+
+    #[test]
+    fn test_from_file_falls_back_to_defaults_without_tool_table() {
+        let temp_dir = tempdir().unwrap();
+        let config_path = temp_dir.path().join("custom.toml");
+        fs::write(&config_path, "").unwrap();
+
+        let result = DjangoFmtOptions::from_file(&config_path).unwrap();
+        assert_eq!(result, DjangoFmtOptions::default());
+    }
 }
@@ -0,0 +1,119 @@
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
+
+#[cfg(test)]
+use std::fs;
+
+use crate::options::{
+    find_config_file, load_options_from_config_file, ConfigError, DjangoFmtOptions,
+};
+
+/// Resolves the effective [`DjangoFmtOptions`] for each file in a tree that may contain several
+/// config files, caching the parsed options per config directory so that a shared config is only
+/// read and parsed once no matter how many files it governs.
+#[derive(Default)]
+pub struct Resolver {
+    /// Parsed options keyed by the directory containing the config file that produced them.
+    cache: HashMap<PathBuf, DjangoFmtOptions>,
+}
+
+impl Resolver {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Resolves the [`DjangoFmtOptions`] that govern `path`, walking its ancestors and returning
+    /// the options for the first directory with a cached (or discoverable) config file.
+    ///
+    /// Returns `Ok(None)` if no config file is found anywhere above `path`.
+    pub fn resolve<P: AsRef<Path>>(
+        &mut self,
+        path: P,
+    ) -> Result<Option<DjangoFmtOptions>, ConfigError> {
+        let path = path.as_ref();
+
+        for directory in path.ancestors() {
+            if let Some(options) = self.cache.get(directory) {
+                return Ok(Some(options.clone()));
+            }
+        }
+
+        let Some(config_path) = find_config_file(path) else {
+            return Ok(None);
+        };
+        let Some(config_dir) = config_path.parent().map(Path::to_path_buf) else {
+            return Ok(None);
+        };
+        let Some(options) = load_options_from_config_file(&config_path)? else {
+            return Ok(None);
+        };
+
+        self.cache.insert(config_dir, options.clone());
+        Ok(Some(options))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_resolve_returns_none_without_pyproject_toml() {
+        let temp_dir = tempdir().unwrap();
+        let mut resolver = Resolver::new();
+        assert_eq!(
+            resolver.resolve(temp_dir.path().join("file.html")).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_resolve_reuses_cached_config_for_sibling_files() {
+        let temp_dir = tempdir().unwrap();
+        let pyproject_path = temp_dir.path().join("pyproject.toml");
+        fs::write(&pyproject_path, "[tool.djangofmt]\nline_length=100\n").unwrap();
+
+        let mut resolver = Resolver::new();
+        let first = resolver
+            .resolve(temp_dir.path().join("a.html"))
+            .unwrap()
+            .unwrap();
+        let second = resolver
+            .resolve(temp_dir.path().join("b.html"))
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(first.line_length, 100);
+        assert_eq!(first, second);
+        assert_eq!(resolver.cache.len(), 1);
+    }
+
+    #[test]
+    fn test_resolve_uses_closest_ancestor_config() {
+        let root_dir = tempdir().unwrap();
+        fs::write(
+            root_dir.path().join("pyproject.toml"),
+            "[tool.djangofmt]\nline_length=100\n",
+        )
+        .unwrap();
+
+        let sub_dir = root_dir.path().join("app");
+        fs::create_dir(&sub_dir).unwrap();
+        fs::write(
+            sub_dir.join("pyproject.toml"),
+            "[tool.djangofmt]\nline_length=80\n",
+        )
+        .unwrap();
+
+        let mut resolver = Resolver::new();
+        let options = resolver
+            .resolve(sub_dir.join("template.html"))
+            .unwrap()
+            .unwrap();
+        assert_eq!(options.line_length, 80);
+    }
+}